@@ -1,12 +1,14 @@
 pub mod infer;
+use std::borrow::Cow;
+
 use chrono::DateTime;
 mod patterns;
 mod strptime;
 
-use chrono::ParseError;
 pub use patterns::Pattern;
 #[cfg(feature = "dtype-time")]
 use polars_core::chunked_array::temporal::time_to_time64ns;
+use regex::Regex;
 
 use super::*;
 #[cfg(feature = "dtype-date")]
@@ -14,70 +16,123 @@ use crate::chunkedarray::date::naive_date_to_date;
 use crate::prelude::utf8::strptime::StrpTimeState;
 
 #[cfg(feature = "dtype-time")]
-fn time_pattern<F, K>(val: &str, convert: F) -> Option<&'static str>
-// (string, fmt) -> PolarsResult
-where
-    F: Fn(&str, &str) -> chrono::ParseResult<K>,
-{
-    ["%T", "%T%.3f", "%T%.6f", "%T%.9f"]
+const TIME_PATTERNS: [&str; 4] = ["%T", "%T%.3f", "%T%.6f", "%T%.9f"];
+
+/// How many non-null values `sniff_samples` pulls out of a column to vote on a format.
+const SNIFF_SAMPLE_SIZE: usize = 1000;
+
+/// Take an evenly spaced sample of up to `SNIFF_SAMPLE_SIZE` non-null values from `ca`,
+/// so format inference isn't fooled by an unrepresentative first row.
+fn sniff_samples(ca: &Utf8Chunked) -> PolarsResult<Vec<&str>> {
+    let step = (ca.len() / SNIFF_SAMPLE_SIZE).max(1);
+    let samples: Vec<&str> = ca
         .into_iter()
-        .find(|&fmt| convert(val, fmt).is_ok())
+        .step_by(step)
+        .flatten()
+        .take(SNIFF_SAMPLE_SIZE)
+        .collect();
+    if samples.is_empty() {
+        polars_bail!(ComputeError:
+            "unable to determine date parsing format, all values are null",
+        );
+    }
+    Ok(samples)
 }
 
-fn datetime_pattern<F, K>(val: &str, convert: F) -> Option<&'static str>
-// (string, fmt) -> PolarsResult
+/// Try every `fmt` in `candidates` against every value in `samples`, discard any `fmt` that
+/// doesn't parse at least one sample, and return the survivor with the most successful
+/// parses. Ties are broken in favor of whichever candidate sorts first in `candidates` --
+/// callers should list the ISO `Y_M_D` patterns ahead of the `D_M_Y` ones, and overlapping
+/// patterns (e.g. `%T` vs `%T%.3f`, both of which parse a plain `"12:34:56"`) resolve to the
+/// first-listed one rather than erroring. Only bail with a descriptive `ComputeError` when no
+/// single candidate parses the *whole* sample -- that's the case where two or more orderings
+/// (e.g. `D/M/Y` vs `M/D/Y`) are genuinely ambiguous and guessing would silently corrupt data.
+fn vote_fmt<F, K>(samples: &[&str], candidates: &[&'static str], parse: F, kind: &str) -> PolarsResult<&'static str>
 where
     F: Fn(&str, &str) -> chrono::ParseResult<K>,
 {
-    let result = patterns::DATETIME_Y_M_D
+    let mut survivors: Vec<(&'static str, usize)> = candidates
         .iter()
-        .find(|fmt| convert(val, fmt).is_ok())
-        .copied();
-    result.or_else(|| {
-        patterns::DATETIME_D_M_Y
-            .iter()
-            .find(|fmt| convert(val, fmt).is_ok())
-            .copied()
-    })
-}
+        .map(|&fmt| (fmt, samples.iter().filter(|s| parse(s, fmt).is_ok()).count()))
+        .filter(|&(_, successes)| successes > 0)
+        .collect();
+    if survivors.is_empty() {
+        polars_bail!(parse_fmt_idk = kind);
+    }
+    // Stable sort: among ties, whichever candidate sorts first in `candidates` wins.
+    survivors.sort_by(|a, b| b.1.cmp(&a.1));
 
-fn date_pattern<F, K>(val: &str, convert: F) -> Option<&'static str>
-// (string, fmt) -> PolarsResult
-where
-    F: Fn(&str, &str) -> chrono::ParseResult<K>,
-{
-    let result = patterns::DATE_Y_M_D
+    let (best_fmt, best_count) = survivors[0];
+    if best_count == samples.len() {
+        return Ok(best_fmt);
+    }
+
+    let conflicting = survivors
         .iter()
-        .find(|fmt| convert(val, fmt).is_ok())
-        .copied();
-    result.or_else(|| {
-        patterns::DATE_D_M_Y
-            .iter()
-            .find(|fmt| convert(val, fmt).is_ok())
-            .copied()
-    })
+        .filter(|(_, n)| *n == best_count)
+        .map(|(fmt, n)| format!("'{fmt}' ({n}/{} values)", samples.len()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    polars_bail!(
+        ComputeError:
+        "unable to determine a single {} format from the sampled values, best candidate(s) \
+        parsed only some of them: {}",
+        kind, conflicting
+    );
 }
 
-struct ParseErrorByteCopy(ParseErrorKind);
-
-impl From<ParseError> for ParseErrorByteCopy {
-    fn from(e: ParseError) -> Self {
-        // we need to do this until chrono ParseErrorKind is public
-        // blocked by https://github.com/chronotope/chrono/pull/588
-        unsafe { std::mem::transmute(e) }
+/// Translate a strptime format string into a regex that matches (but does not necessarily
+/// validate) an occurrence of that format anywhere in a larger string. Used by the
+/// `_not_exact` variants to locate the date/time substring before handing it to chrono.
+fn fmt_to_regex(fmt: &str) -> String {
+    let mut pattern = String::with_capacity(fmt.len() * 2);
+    let mut chars = fmt.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            pattern.push_str(&regex::escape(&c.to_string()));
+            continue;
+        }
+        match chars.next() {
+            Some('Y') => pattern.push_str(r"\d{4}"),
+            Some('y') => pattern.push_str(r"\d{2}"),
+            Some('m' | 'd' | 'H' | 'M' | 'S' | 'e') => pattern.push_str(r"\d{1,2}"),
+            Some('b' | 'B' | 'a' | 'A' | 'Z') => pattern.push_str(r"[A-Za-z]+"),
+            Some('p') => pattern.push_str(r"[AaPp][Mm]"),
+            Some('z') => pattern.push_str(r"[+-]\d{4}"),
+            Some(':') if chars.peek() == Some(&'z') => {
+                chars.next();
+                pattern.push_str(r"[+-]\d{2}:\d{2}");
+            },
+            Some('.') => {
+                // `%.f`, or the width-qualified `%.3f`/`%.6f`/`%.9f` forms used throughout
+                // this module's own datetime pattern lists.
+                let mut lookahead = chars.clone();
+                while matches!(lookahead.peek(), Some(d) if d.is_ascii_digit()) {
+                    lookahead.next();
+                }
+                if lookahead.peek() == Some(&'f') {
+                    lookahead.next();
+                    chars = lookahead;
+                    pattern.push_str(r"(?:\.\d+)?");
+                } else {
+                    pattern.push_str(r"\S+?");
+                }
+            },
+            Some(_other) => {
+                // Unknown specifier: match permissively instead of emitting a literal `%x`
+                // sequence, which could never occur in real input and would make the whole
+                // not_exact scan fail silently.
+                pattern.push_str(r"\S+?");
+            },
+            None => pattern.push('%'),
+        }
     }
+    pattern
 }
 
-#[allow(dead_code)]
-enum ParseErrorKind {
-    OutOfRange,
-    Impossible,
-    NotEnough,
-    Invalid,
-    /// The input string has been prematurely ended.
-    TooShort,
-    TooLong,
-    BadFormat,
+fn compile_not_exact_regex(fmt: &str) -> PolarsResult<Regex> {
+    Regex::new(&fmt_to_regex(fmt))
+        .map_err(|e| polars_err!(ComputeError: "could not turn format '{}' into a regex: {}", fmt, e))
 }
 
 fn get_first_val(ca: &Utf8Chunked) -> PolarsResult<&str> {
@@ -89,34 +144,183 @@ fn get_first_val(ca: &Utf8Chunked) -> PolarsResult<&str> {
     Ok(ca.get(idx).expect("should not be null"))
 }
 
+/// Sentinel `fmt` values recognized by [`Utf8Methods::as_datetime`] that bypass strptime
+/// entirely and dispatch straight to chrono's RFC parsers.
+const FMT_RFC2822: &str = "rfc2822";
+const FMT_RFC3339: &str = "rfc3339";
+
+/// Sentinel `fmt` accepting either a literal `T` or a space between the date and time
+/// components, so a column mixing `2021-01-01T00:00:00` and `2021-01-01 00:00:00` rows
+/// parses under one format instead of needing `patterns::DATETIME_Y_M_D`'s separate
+/// `T`- and space-separated entries.
+const FMT_ISO: &str = "iso";
+
+fn looks_like_iso(val: &str) -> bool {
+    static ISO_SHAPE: &str = r"^\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2}(:\d{2})?(\.\d+)?([+-]\d{2}:?\d{2}|Z)?$";
+    Regex::new(ISO_SHAPE).unwrap().is_match(val)
+}
+
+/// Parse a single ISO 8601-shaped value, accepting either `T` or a space as the date/time
+/// separator and treating the seconds, fractional seconds and offset as optional.
+fn parse_iso_datetime(s: &str, tu: TimeUnit) -> Option<i64> {
+    let func = match tu {
+        TimeUnit::Nanoseconds => datetime_to_timestamp_ns,
+        TimeUnit::Microseconds => datetime_to_timestamp_us,
+        TimeUnit::Milliseconds => datetime_to_timestamp_ms,
+    };
+    // Normalize the separator so a single set of chrono formats covers both variants.
+    let normalized: Cow<str> = if s.as_bytes().get(10) == Some(&b' ') {
+        let mut owned = s.to_string();
+        owned.replace_range(10..11, "T");
+        Cow::Owned(owned)
+    } else {
+        Cow::Borrowed(s)
+    };
+
+    if let Ok(dt) = DateTime::parse_from_rfc3339(&normalized) {
+        return Some(func(dt.naive_utc()));
+    }
+    // `%z` accepts both `+HH:MM` and `+HHMM`, covering offsets RFC 3339 rejects.
+    const OFFSET_FMTS: [&str; 2] = ["%Y-%m-%dT%H:%M:%S%.f%z", "%Y-%m-%dT%H:%M%z"];
+    if let Some(dt) = OFFSET_FMTS
+        .iter()
+        .find_map(|fmt| DateTime::parse_from_str(&normalized, fmt).ok())
+    {
+        return Some(func(dt.naive_utc()));
+    }
+    const FMTS: [&str; 4] = [
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%dT%H:%MZ",
+    ];
+    FMTS.iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(&normalized, fmt).ok())
+        .map(func)
+}
+
+/// chrono treats a `-0000` offset (“unknown/unspecified zone”) as distinct from `+0000`,
+/// even though both mean UTC for our purposes here.
+fn parse_rfc2822(s: &str) -> chrono::ParseResult<DateTime<chrono::FixedOffset>> {
+    DateTime::parse_from_rfc2822(s).or_else(|e| {
+        if let Some(stripped) = s.strip_suffix("-0000") {
+            DateTime::parse_from_rfc2822(&format!("{stripped}+0000"))
+        } else {
+            Err(e)
+        }
+    })
+}
+
 #[cfg(feature = "dtype-datetime")]
 fn sniff_fmt_datetime(ca_utf8: &Utf8Chunked) -> PolarsResult<&'static str> {
     let val = get_first_val(ca_utf8)?;
-    match datetime_pattern(val, NaiveDateTime::parse_from_str) {
-        Some(pattern) => Ok(pattern),
-        None => match datetime_pattern(val, NaiveDate::parse_from_str) {
-            Some(pattern) => Ok(pattern),
-            None => polars_bail!(parse_fmt_idk = "datetime"),
-        },
+    if DateTime::parse_from_rfc3339(val).is_ok() {
+        return Ok(FMT_RFC3339);
+    }
+    if parse_rfc2822(val).is_ok() {
+        return Ok(FMT_RFC2822);
     }
+    if looks_like_iso(val) {
+        return Ok(FMT_ISO);
+    }
+    let samples = sniff_samples(ca_utf8)?;
+    let datetime_candidates: Vec<&'static str> = patterns::DATETIME_Y_M_D
+        .iter()
+        .chain(patterns::DATETIME_D_M_Y.iter())
+        .copied()
+        .collect();
+    vote_fmt(&samples, &datetime_candidates, NaiveDateTime::parse_from_str, "datetime").or_else(|_| {
+        let date_candidates: Vec<&'static str> = patterns::DATE_Y_M_D
+            .iter()
+            .chain(patterns::DATE_D_M_Y.iter())
+            .copied()
+            .collect();
+        vote_fmt(&samples, &date_candidates, NaiveDate::parse_from_str, "date")
+    })
 }
 
 #[cfg(feature = "dtype-date")]
 fn sniff_fmt_date(ca_utf8: &Utf8Chunked) -> PolarsResult<&'static str> {
-    let val = get_first_val(ca_utf8)?;
-    if let Some(pattern) = date_pattern(val, NaiveDate::parse_from_str) {
-        return Ok(pattern);
-    }
-    polars_bail!(parse_fmt_idk = "date");
+    let samples = sniff_samples(ca_utf8)?;
+    let candidates: Vec<&'static str> = patterns::DATE_Y_M_D
+        .iter()
+        .chain(patterns::DATE_D_M_Y.iter())
+        .copied()
+        .collect();
+    vote_fmt(&samples, &candidates, NaiveDate::parse_from_str, "date")
 }
 
 #[cfg(feature = "dtype-time")]
 fn sniff_fmt_time(ca_utf8: &Utf8Chunked) -> PolarsResult<&'static str> {
-    let val = get_first_val(ca_utf8)?;
-    if let Some(pattern) = time_pattern(val, NaiveTime::parse_from_str) {
-        return Ok(pattern);
+    let samples = sniff_samples(ca_utf8)?;
+    vote_fmt(&samples, &TIME_PATTERNS, NaiveTime::parse_from_str, "time")
+}
+
+/// Parse a column of RFC 2822 or RFC 3339 timestamps, producing a tz-aware `DatetimeChunked`
+/// in UTC with each value's original offset already applied.
+#[cfg(feature = "dtype-datetime")]
+fn as_datetime_rfc(
+    utf8_ca: &Utf8Chunked,
+    fmt: &str,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+) -> PolarsResult<DatetimeChunked> {
+    let func = match tu {
+        TimeUnit::Nanoseconds => datetime_to_timestamp_ns,
+        TimeUnit::Microseconds => datetime_to_timestamp_us,
+        TimeUnit::Milliseconds => datetime_to_timestamp_ms,
+    };
+    let parse = |s: &str| -> Option<DateTime<chrono::FixedOffset>> {
+        match fmt {
+            FMT_RFC2822 => parse_rfc2822(s).ok(),
+            FMT_RFC3339 => DateTime::parse_from_rfc3339(s).ok(),
+            _ => unreachable!("as_datetime_rfc called with non-RFC fmt"),
+        }
+    };
+    let mut ca: Int64Chunked = utf8_ca
+        .into_iter()
+        .map(|opt_s| opt_s.and_then(|s| parse(s).map(|dt| func(dt.naive_utc()))))
+        .collect_trusted();
+    ca.rename(utf8_ca.name());
+    if tz.is_some() {
+        // Each value already carries its own offset, so the column is an absolute UTC
+        // instant per row; there's no single wall-clock reinterpretation of `tz` that would
+        // make sense the way `replace_time_zone` applies one to naive timestamps elsewhere
+        // in this file, so surface that explicitly instead of silently dropping `tz`.
+        polars_bail!(
+            ComputeError:
+            "`tz` is not supported together with an RFC 2822/3339 datetime format; \
+            the parsed result is already UTC-based"
+        );
+    }
+    Ok(ca.into_datetime(tu, Some("UTC".to_string())))
+}
+
+/// Regex matching an embedded (not necessarily whole-string) occurrence of the shape that
+/// `fmt` parses, for the sentinel formats that aren't expressible via [`fmt_to_regex`].
+fn sentinel_not_exact_regex(fmt: &str) -> PolarsResult<Regex> {
+    let pattern = match fmt {
+        FMT_RFC2822 => r"(?:[A-Za-z]{3},\s*)?\d{1,2}\s+[A-Za-z]{3}\s+\d{4}\s+\d{2}:\d{2}:\d{2}\s+[+-]\d{4}",
+        FMT_RFC3339 | FMT_ISO => r"\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2}(?::\d{2})?(?:\.\d+)?(?:[+-]\d{2}:?\d{2}|Z)?",
+        _ => unreachable!("sentinel_not_exact_regex called with non-sentinel fmt"),
+    };
+    Regex::new(pattern)
+        .map_err(|e| polars_err!(ComputeError: "could not compile sentinel regex for format '{}': {}", fmt, e))
+}
+
+/// Parse a substring already known to match [`sentinel_not_exact_regex`] for `fmt`.
+fn parse_sentinel(fmt: &str, s: &str, tu: TimeUnit) -> Option<i64> {
+    let func = match tu {
+        TimeUnit::Nanoseconds => datetime_to_timestamp_ns,
+        TimeUnit::Microseconds => datetime_to_timestamp_us,
+        TimeUnit::Milliseconds => datetime_to_timestamp_ms,
+    };
+    match fmt {
+        FMT_RFC2822 => parse_rfc2822(s).ok().map(|dt| func(dt.naive_utc())),
+        FMT_RFC3339 => DateTime::parse_from_rfc3339(s).ok().map(|dt| func(dt.naive_utc())),
+        FMT_ISO => parse_iso_datetime(s, tu),
+        _ => unreachable!("parse_sentinel called with non-sentinel fmt"),
     }
-    polars_bail!(parse_fmt_idk = "time");
 }
 
 pub trait Utf8Methods: AsUtf8 {
@@ -191,34 +395,23 @@ pub trait Utf8Methods: AsUtf8 {
             Some(fmt) => fmt,
             None => sniff_fmt_date(utf8_ca)?,
         };
+        if matches!(fmt, FMT_RFC2822 | FMT_RFC3339 | FMT_ISO) {
+            polars_bail!(
+                ComputeError:
+                "format '{}' produces a datetime, not a date; use as_datetime_not_exact instead",
+                fmt
+            );
+        }
+        let reg = compile_not_exact_regex(fmt)?;
         let mut ca: Int32Chunked = utf8_ca
             .into_iter()
-            .map(|opt_s| match opt_s {
-                None => None,
-                Some(mut s) => {
-                    let fmt_len = fmt.len();
-
-                    for i in 1..(s.len().saturating_sub(fmt_len)) {
-                        if s.is_empty() {
-                            return None;
-                        }
-                        match NaiveDate::parse_from_str(s, fmt).map(naive_date_to_date) {
-                            Ok(nd) => return Some(nd),
-                            Err(e) => {
-                                let e: ParseErrorByteCopy = e.into();
-                                match e.0 {
-                                    ParseErrorKind::TooLong => {
-                                        s = &s[..s.len() - 1];
-                                    },
-                                    _ => {
-                                        s = &s[i..];
-                                    },
-                                }
-                            },
-                        }
-                    }
-                    None
-                },
+            .map(|opt_s| {
+                let s = opt_s?;
+                reg.find_iter(s).find_map(|m| {
+                    NaiveDate::parse_from_str(m.as_str(), fmt)
+                        .ok()
+                        .map(naive_date_to_date)
+                })
             })
             .collect_trusted();
         ca.rename(utf8_ca.name());
@@ -243,44 +436,47 @@ pub trait Utf8Methods: AsUtf8 {
             None => sniff_fmt_datetime(utf8_ca)?,
         };
 
+        if matches!(fmt, FMT_RFC2822 | FMT_RFC3339 | FMT_ISO) {
+            let reg = sentinel_not_exact_regex(fmt)?;
+            let mut ca: Int64Chunked = utf8_ca
+                .into_iter()
+                .map(|opt_s| {
+                    let s = opt_s?;
+                    reg.find_iter(s).find_map(|m| parse_sentinel(fmt, m.as_str(), tu))
+                })
+                .collect_trusted();
+            ca.rename(utf8_ca.name());
+            return match (tz_aware, tz) {
+                #[cfg(feature = "timezones")]
+                (false, Some(tz)) => {
+                    polars_ops::prelude::replace_time_zone(&ca.into_datetime(tu, None), Some(tz), _use_earliest)
+                },
+                #[cfg(feature = "timezones")]
+                (true, _) => Ok(ca.into_datetime(tu, Some("UTC".to_string()))),
+                _ => Ok(ca.into_datetime(tu, None)),
+            };
+        }
+
         let func = match tu {
             TimeUnit::Nanoseconds => datetime_to_timestamp_ns,
             TimeUnit::Microseconds => datetime_to_timestamp_us,
             TimeUnit::Milliseconds => datetime_to_timestamp_ms,
         };
 
+        let reg = compile_not_exact_regex(fmt)?;
         let mut ca: Int64Chunked = utf8_ca
             .into_iter()
-            .map(|opt_s| match opt_s {
-                None => None,
-                Some(mut s) => {
-                    let fmt_len = fmt.len();
-
-                    for i in 1..(s.len().saturating_sub(fmt_len)) {
-                        if s.is_empty() {
-                            return None;
-                        }
-                        let timestamp = match tz_aware {
-                            true => DateTime::parse_from_str(s, fmt).map(|dt| func(dt.naive_utc())),
-                            false => NaiveDateTime::parse_from_str(s, fmt).map(func),
-                        };
-                        match timestamp {
-                            Ok(ts) => return Some(ts),
-                            Err(e) => {
-                                let e: ParseErrorByteCopy = e.into();
-                                match e.0 {
-                                    ParseErrorKind::TooLong => {
-                                        s = &s[..s.len() - 1];
-                                    },
-                                    _ => {
-                                        s = &s[i..];
-                                    },
-                                }
-                            },
-                        }
+            .map(|opt_s| {
+                let s = opt_s?;
+                reg.find_iter(s).find_map(|m| {
+                    if tz_aware {
+                        DateTime::parse_from_str(m.as_str(), fmt)
+                            .ok()
+                            .map(|dt| func(dt.naive_utc()))
+                    } else {
+                        NaiveDateTime::parse_from_str(m.as_str(), fmt).ok().map(func)
                     }
-                    None
-                },
+                })
             })
             .collect_trusted();
         ca.rename(utf8_ca.name());
@@ -389,6 +585,39 @@ pub trait Utf8Methods: AsUtf8 {
             Some(fmt) => fmt,
             None => return infer::to_datetime(utf8_ca, tu, tz, use_earliest),
         };
+        if fmt == FMT_RFC2822 || fmt == FMT_RFC3339 {
+            return as_datetime_rfc(utf8_ca, fmt, tu, tz);
+        }
+        if fmt == FMT_ISO {
+            let cache = cache && utf8_ca.len() > 50;
+            let mut cache_map = PlHashMap::new();
+            let mut convert = |s: &str| parse_iso_datetime(s, tu);
+            let mut ca: Int64Chunked = utf8_ca
+                .into_iter()
+                .map(|opt_s| {
+                    opt_s.and_then(|s| {
+                        if cache {
+                            *cache_map.entry(s).or_insert_with(|| convert(s))
+                        } else {
+                            convert(s)
+                        }
+                    })
+                })
+                .collect_trusted();
+            ca.rename(utf8_ca.name());
+            // `parse_iso_datetime` already normalizes any offset it finds to a UTC instant,
+            // so a tz-aware caller gets a "UTC"-labeled result the same way as_datetime_rfc's
+            // RFC 2822/3339 path does, instead of being mislabeled as a naive timestamp.
+            return match (tz_aware, tz) {
+                #[cfg(feature = "timezones")]
+                (false, Some(tz)) => {
+                    polars_ops::prelude::replace_time_zone(&ca.into_datetime(tu, None), Some(tz), use_earliest)
+                },
+                #[cfg(feature = "timezones")]
+                (true, _) => Ok(ca.into_datetime(tu, Some("UTC".to_string()))),
+                _ => Ok(ca.into_datetime(tu, None)),
+            };
+        }
         let fmt = strptime::compile_fmt(fmt)?;
         let cache = cache && utf8_ca.len() > 50;
 
@@ -404,10 +633,25 @@ pub trait Utf8Methods: AsUtf8 {
                 use polars_arrow::export::hashbrown::hash_map::Entry;
                 let mut cache_map = PlHashMap::new();
 
-                let convert = |s: &str| {
-                    DateTime::parse_from_str(s, &fmt)
-                        .ok()
-                        .map(|dt| func(dt.naive_utc()))
+                // `StrpTimeState` understands `%z`/`%:z` offsets and `%.3f`/`%.6f`/`%.9f`
+                // fractions directly (a plain `%.f` goes to chrono),
+                // baking the offset into the UTC timestamp it returns, so tz-aware ISO columns
+                // can skip `chrono::DateTime::parse_from_str` the same way offset-free ones do.
+                let mut strptime_cache = StrpTimeState::default();
+                let fmt_len = strptime::fmt_len(fmt.as_bytes());
+                let mut convert = |s: &str| {
+                    fmt_len
+                        .and_then(|fmt_len| {
+                            // Safety:
+                            // fmt_len is correct, it was computed with this `fmt` str.
+                            unsafe { strptime_cache.parse(s.as_bytes(), fmt.as_bytes(), fmt_len) }
+                        })
+                        .map(func)
+                        .or_else(|| {
+                            DateTime::parse_from_str(s, &fmt)
+                                .ok()
+                                .map(|dt| func(dt.naive_utc()))
+                        })
                 };
 
                 let mut ca: Int64Chunked = utf8_ca
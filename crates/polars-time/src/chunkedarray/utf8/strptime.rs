@@ -0,0 +1,200 @@
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+
+use super::*;
+
+/// Per-format scratch state for the byte-level strptime fast path. Reused across rows in a
+/// column so the hot loop stays allocation-free.
+#[derive(Default)]
+pub struct StrpTimeState {
+    /// `fmt` tokenized on the first call to [`StrpTimeState::parse`], reused for later rows.
+    specs: Option<Vec<Spec>>,
+}
+
+/// A single fixed-width token of a strptime format, as understood by the byte-level parser.
+#[derive(Clone, Copy)]
+enum Spec {
+    Year4,
+    Year2,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    /// `%z`: `+HHMM` / `-HHMM`.
+    Offset,
+    /// `%:z`: `+HH:MM` / `-HH:MM`.
+    OffsetColon,
+    /// `%.3f` / `%.6f` / `%.9f`: a literal `.` followed by `n` fractional-second digits.
+    Fraction(u32),
+    Literal(u8),
+}
+
+fn spec_len(spec: Spec) -> usize {
+    match spec {
+        Spec::Year4 => 4,
+        Spec::Year2 | Spec::Month | Spec::Day | Spec::Hour | Spec::Minute | Spec::Second => 2,
+        Spec::Offset => 5,
+        Spec::OffsetColon => 6,
+        Spec::Fraction(n) => 1 + n as usize,
+        Spec::Literal(_) => 1,
+    }
+}
+
+/// Translate `fmt` into a sequence of fixed-width tokens, or `None` if it contains any
+/// specifier whose width isn't statically known (in which case callers fall back to chrono).
+fn parse_spec(fmt: &[u8]) -> Option<Vec<Spec>> {
+    let mut specs = Vec::new();
+    let mut i = 0;
+    while i < fmt.len() {
+        if fmt[i] != b'%' {
+            specs.push(Spec::Literal(fmt[i]));
+            i += 1;
+            continue;
+        }
+        i += 1;
+        let spec = match *fmt.get(i)? {
+            b'Y' => Spec::Year4,
+            b'y' => Spec::Year2,
+            b'm' => Spec::Month,
+            b'd' => Spec::Day,
+            b'H' => Spec::Hour,
+            b'M' => Spec::Minute,
+            b'S' => Spec::Second,
+            b'z' => Spec::Offset,
+            b':' if fmt.get(i + 1) == Some(&b'z') => {
+                i += 1;
+                Spec::OffsetColon
+            },
+            b'.' => {
+                let n = match fmt.get(i + 1) {
+                    Some(b'3') => 3,
+                    Some(b'6') => 6,
+                    Some(b'9') => 9,
+                    _ => return None,
+                };
+                if fmt.get(i + 2) != Some(&b'f') {
+                    return None;
+                }
+                i += 2;
+                Spec::Fraction(n)
+            },
+            _ => return None,
+        };
+        specs.push(spec);
+        i += 1;
+    }
+    Some(specs)
+}
+
+/// Total byte length `fmt` occupies once every specifier has consumed its fixed-width input,
+/// or `None` if `fmt` isn't fixed-width (callers then fall back to chrono's general parser).
+pub fn fmt_len(fmt: &[u8]) -> Option<usize> {
+    let specs = parse_spec(fmt)?;
+    Some(specs.into_iter().map(spec_len).sum())
+}
+
+/// Normalizing a user-supplied format ahead of the parse loop. Currently a no-op: formats
+/// are used as-is, this is the hook future normalization (e.g. locale-specific aliases) would
+/// live in.
+pub fn compile_fmt(fmt: &str) -> PolarsResult<String> {
+    Ok(fmt.to_string())
+}
+
+fn parse_u32(bytes: &[u8]) -> Option<u32> {
+    if !bytes.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    std::str::from_utf8(bytes).ok()?.parse().ok()
+}
+
+impl StrpTimeState {
+    /// Parse `val` against `fmt`, whose fixed byte length is `fmt_len` (as returned by
+    /// [`fmt_len`] for this exact `fmt`). When `fmt` contains a `%z`/`%:z` offset, the
+    /// returned `NaiveDateTime` has that offset already subtracted, i.e. it's UTC, matching
+    /// what callers get from `chrono::DateTime::parse_from_str(..).naive_utc()`.
+    ///
+    /// # Safety
+    /// `fmt_len` must have been computed from this exact `fmt` via [`fmt_len`]; token chunks
+    /// are sliced out of `val` by their statically known width, and the single
+    /// `val.len() == fmt_len` guard below is the only check that `val` has that many bytes: a
+    /// mismatched `fmt_len` either panics on an out-of-range slice or leaves trailing bytes
+    /// unchecked.
+    pub unsafe fn parse(&mut self, val: &[u8], fmt: &[u8], fmt_len: usize) -> Option<NaiveDateTime> {
+        if val.len() != fmt_len {
+            return None;
+        }
+        if self.specs.is_none() {
+            self.specs = Some(parse_spec(fmt)?);
+        }
+        let specs = self.specs.as_deref()?;
+
+        let mut year = 0i32;
+        let mut month = 1u32;
+        let mut day = 1u32;
+        let mut hour = 0u32;
+        let mut minute = 0u32;
+        let mut second = 0u32;
+        let mut nano = 0u32;
+        let mut offset_minutes = 0i32;
+
+        let mut pos = 0usize;
+        for &spec in specs {
+            let len = spec_len(spec);
+            let chunk = &val[pos..pos + len];
+            pos += len;
+            match spec {
+                Spec::Year4 => year = parse_u32(chunk)? as i32,
+                Spec::Year2 => {
+                    let yy = parse_u32(chunk)?;
+                    // Matches chrono's pivot for `%y`: 00-69 -> 2000-2069, 70-99 -> 1970-1999.
+                    year = if yy < 70 { 2000 + yy as i32 } else { 1900 + yy as i32 };
+                },
+                Spec::Month => month = parse_u32(chunk)?,
+                Spec::Day => day = parse_u32(chunk)?,
+                Spec::Hour => hour = parse_u32(chunk)?,
+                Spec::Minute => minute = parse_u32(chunk)?,
+                Spec::Second => second = parse_u32(chunk)?,
+                Spec::Fraction(n) => {
+                    if chunk[0] != b'.' {
+                        return None;
+                    }
+                    let digits = parse_u32(&chunk[1..])?;
+                    nano = digits * 10u32.pow(9 - n);
+                },
+                Spec::Offset => {
+                    let sign = match chunk[0] {
+                        b'+' => 1,
+                        b'-' => -1,
+                        _ => return None,
+                    };
+                    let hh = parse_u32(&chunk[1..3])?;
+                    let mm = parse_u32(&chunk[3..5])?;
+                    offset_minutes = sign * (hh as i32 * 60 + mm as i32);
+                },
+                Spec::OffsetColon => {
+                    let sign = match chunk[0] {
+                        b'+' => 1,
+                        b'-' => -1,
+                        _ => return None,
+                    };
+                    if chunk[3] != b':' {
+                        return None;
+                    }
+                    let hh = parse_u32(&chunk[1..3])?;
+                    let mm = parse_u32(&chunk[4..6])?;
+                    offset_minutes = sign * (hh as i32 * 60 + mm as i32);
+                },
+                Spec::Literal(b) => {
+                    if chunk[0] != b {
+                        return None;
+                    }
+                },
+            }
+        }
+
+        let date = NaiveDate::from_ymd_opt(year, month, day)?;
+        let time = NaiveTime::from_hms_nano_opt(hour, minute, second, nano)?;
+        let ndt = NaiveDateTime::new(date, time);
+        Some(ndt - Duration::minutes(offset_minutes as i64))
+    }
+}